@@ -1,7 +1,7 @@
 //! 本文件提供了一个Context结构，用于存储生成的C语言代码。
 //! 是树的根节点
 
-use crate::CFunction;
+use crate::{ CFunction, CStruct };
 
 /// Context 上下文
 /// 用于存储生成的C语言代码
@@ -48,6 +48,119 @@ impl Context {
         self.add_ccode(&format!("#include \"{}\"\n", head_file_to_include));
     }
 
+    /// 添加系统头文件
+    /// # Examples
+    /// ```
+    /// use ccgenor::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.add_system_include("stdio.h");
+    /// assert_eq!(ctx.get_ccode(), "#include <stdio.h>\n");
+    /// ```
+    pub fn add_system_include(&mut self, head_file_to_include: &str) {
+        self.add_ccode(&format!("#include <{}>\n", head_file_to_include));
+    }
+
+    /// 添加带值的宏定义
+    /// # Examples
+    /// ```
+    /// use ccgenor::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.add_define("MAX_LEN", "1024");
+    /// assert_eq!(ctx.get_ccode(), "#define MAX_LEN 1024\n");
+    /// ```
+    pub fn add_define(&mut self, name: &str, value: &str) {
+        self.add_ccode(&format!("#define {} {}\n", name, value));
+    }
+
+    /// 添加无值的宏定义（开关宏）
+    /// # Examples
+    /// ```
+    /// use ccgenor::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.add_define_flag("DEBUG");
+    /// assert_eq!(ctx.get_ccode(), "#define DEBUG\n");
+    /// ```
+    pub fn add_define_flag(&mut self, name: &str) {
+        self.add_ccode(&format!("#define {}\n", name));
+    }
+
+    /// 写入一段定宽 / 语义化类型的 typedef 前言。
+    ///
+    /// 把紧凑的友好名（`i64`、`u32`、`f32` 等，与 [`crate::CType`] 叶子类型
+    /// 渲染出的名字一致）映射到 `<stdint.h>` 的定宽类型上；生成器只要依赖
+    /// 这段前言，就能放心地渲染这些短名字。当 `with_bool_fallback` 为真时，
+    /// 额外补上一段面向 C99 以前目标的 `bool` 兜底定义。
+    ///
+    /// 注意：定宽整数需要调用方自行 `#include <stdint.h>`。
+    ///
+    /// # Examples
+    /// ```
+    /// use ccgenor::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.add_stdint_typedefs(false);
+    /// assert!(ctx.get_ccode().starts_with("typedef int8_t i8;\n"));
+    /// assert!(ctx.get_ccode().contains("typedef unsigned char* byteptr;\n"));
+    /// ```
+    pub fn add_stdint_typedefs(&mut self, with_bool_fallback: bool) {
+        const TYPEDEFS: &[(&str, &str)] = &[
+            ("int8_t", "i8"),
+            ("int16_t", "i16"),
+            ("int32_t", "i32"),
+            ("int64_t", "i64"),
+            ("intptr_t", "isize"),
+            ("uint8_t", "u8"),
+            ("uint16_t", "u16"),
+            ("uint32_t", "u32"),
+            ("uint64_t", "u64"),
+            ("uintptr_t", "usize"),
+            ("float", "f32"),
+            ("double", "f64"),
+            ("void*", "voidptr"),
+            ("unsigned char*", "byteptr"),
+        ];
+
+        for (c_type, friendly) in TYPEDEFS {
+            self.add_ccode(&format!("typedef {} {};\n", c_type, friendly));
+        }
+
+        if with_bool_fallback {
+            self.add_ccode(
+                "#ifndef __cplusplus\ntypedef u8 bool;\n#define true 1\n#define false 0\n#endif\n",
+            );
+        }
+    }
+
+    /// 用头文件保护宏包裹一段生成逻辑。
+    ///
+    /// 先写入 `#ifndef`/`#define`，随后执行 `body_fn` 向当前 Context 追加
+    /// 内容，最后补上 `#endif`，生成符合惯例的头文件骨架。
+    ///
+    /// # Examples
+    /// ```
+    /// use ccgenor::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.wrap_header_guard("FOO_H", |ctx| {
+    ///     ctx.add_system_include("stdint.h");
+    /// });
+    /// assert_eq!(
+    ///     ctx.get_ccode(),
+    ///     "#ifndef FOO_H\n#define FOO_H\n\n#include <stdint.h>\n\n#endif // FOO_H\n"
+    /// );
+    /// ```
+    pub fn wrap_header_guard<F>(&mut self, guard_macro: &str, body_fn: F)
+    where
+        F: FnOnce(&mut Context),
+    {
+        self.add_ccode(&format!("#ifndef {}\n#define {}\n\n", guard_macro, guard_macro));
+        body_fn(self);
+        self.add_ccode(&format!("\n#endif // {}\n", guard_macro));
+    }
+
     /// 添加函数
     /// # Examples
     /// ```
@@ -65,6 +178,22 @@ impl Context {
         self.add_ccode(&func.get_generated_function_body_ccode());
     }
 
+    /// 添加结构体定义
+    /// # Examples
+    /// ```
+    /// use ccgenor::{ Context, CStruct, CType };
+    ///
+    /// let mut ctx = Context::new();
+    /// let mut point = CStruct::new("Point".to_string());
+    /// point.add_member(CType::I32, "x");
+    /// point.add_member(CType::I32, "y");
+    /// ctx.add_struct(&point);
+    /// assert_eq!(ctx.get_ccode(), "struct Point {\n    i32 x;\n    i32 y;\n};\n");
+    /// ```
+    pub fn add_struct(&mut self, cstruct: &CStruct) {
+        self.add_ccode(&cstruct.create_struct_definition());
+    }
+
     /// 打印生成的C语言代码
     pub fn print_ccode(&self) {
         println!("{}", self.ccode);
@@ -90,6 +219,65 @@ mod test {
         assert_eq!(ctx.get_ccode(), "#include \"stdio.h\"\n");
     }
 
+    #[test]
+    fn test_system_include() {
+        let mut ctx = Context::new();
+        ctx.add_system_include("stdio.h");
+        assert_eq!(ctx.get_ccode(), "#include <stdio.h>\n");
+    }
+
+    #[test]
+    fn test_define() {
+        let mut ctx = Context::new();
+        ctx.add_define("MAX_LEN", "1024");
+        ctx.add_define_flag("DEBUG");
+        assert_eq!(ctx.get_ccode(), "#define MAX_LEN 1024\n#define DEBUG\n");
+    }
+
+    #[test]
+    fn test_wrap_header_guard() {
+        let mut ctx = Context::new();
+        ctx.wrap_header_guard("FOO_H", |ctx| {
+            ctx.add_system_include("stdint.h");
+        });
+        assert_eq!(
+            ctx.get_ccode(),
+            "#ifndef FOO_H\n#define FOO_H\n\n#include <stdint.h>\n\n#endif // FOO_H\n"
+        );
+    }
+
+    #[test]
+    fn test_stdint_typedefs() {
+        let mut ctx = Context::new();
+        ctx.add_stdint_typedefs(false);
+        assert_eq!(
+            ctx.get_ccode(),
+            "typedef int8_t i8;\n\
+             typedef int16_t i16;\n\
+             typedef int32_t i32;\n\
+             typedef int64_t i64;\n\
+             typedef intptr_t isize;\n\
+             typedef uint8_t u8;\n\
+             typedef uint16_t u16;\n\
+             typedef uint32_t u32;\n\
+             typedef uint64_t u64;\n\
+             typedef uintptr_t usize;\n\
+             typedef float f32;\n\
+             typedef double f64;\n\
+             typedef void* voidptr;\n\
+             typedef unsigned char* byteptr;\n"
+        );
+    }
+
+    #[test]
+    fn test_stdint_typedefs_bool_fallback() {
+        let mut ctx = Context::new();
+        ctx.add_stdint_typedefs(true);
+        assert!(ctx.get_ccode().ends_with(
+            "#ifndef __cplusplus\ntypedef u8 bool;\n#define true 1\n#define false 0\n#endif\n"
+        ));
+    }
+
     #[test]
     fn test_function() {
         let mut ctx = Context::new();
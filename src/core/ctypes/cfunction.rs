@@ -1,13 +1,17 @@
 //! 本文件提供了 CFunction 结构体的定义，用于描述 C 语言函数的相关信息。
 
-use crate::c_type;
+use crate::CType;
+use crate::core::ctypes::stmt::{ render_block, CExpr, CStmt };
 
 /// CFunction 结构体用于描述 C 语言函数的相关信息。
 pub struct CFunction {
     name: String,
 
-    return_type: c_type,
-    parameters: Vec<c_type>,
+    return_type: CType,
+    parameters: Vec<CType>,
+
+    /// 以语句树形式描述的函数体，由 [`CFunction::build`] 渲染。
+    body: Vec<CStmt>,
 
     generated_function_body_ccode: String,
 }
@@ -33,11 +37,16 @@ impl CFunction {
     ///     vec!["int a", "int b"]
     /// );
     /// ```
-    pub fn new(name: String, return_type: c_type, parameters: Vec<c_type>) -> Self {
+    pub fn new<R, P>(name: String, return_type: R, parameters: Vec<P>) -> Self
+    where
+        R: Into<CType>,
+        P: Into<CType>,
+    {
         Self {
             name,
-            return_type,
-            parameters,
+            return_type: return_type.into(),
+            parameters: parameters.into_iter().map(Into::into).collect(),
+            body: Vec::new(),
             generated_function_body_ccode: String::new(),
         }
     }
@@ -46,11 +55,11 @@ impl CFunction {
         &self.name
     }
 
-    pub fn get_return_type(&self) -> &c_type {
+    pub fn get_return_type(&self) -> &CType {
         &self.return_type
     }
 
-    pub fn get_parameters(&self) -> &[c_type] {
+    pub fn get_parameters(&self) -> &[CType] {
         &self.parameters
     }
 
@@ -62,8 +71,14 @@ impl CFunction {
         self.generated_function_body_ccode = ccode;
     }
 
+    /// 向函数体追加一段原样文本。
+    ///
+    /// 作为语句树时代的兼容垫片，它会把文本包成一个 [`CStmt::Raw`] 节点
+    /// 压入函数体，因此这里追加的内容同样会被 [`CFunction::build`] 渲染出来，
+    /// 不会在与 [`CFunction::push_stmt`] 混用时被悄悄丢掉。
     pub fn append_generated_function_body_ccode(&mut self, ccode: String) {
-        self.generated_function_body_ccode += &ccode;
+        let text = ccode.strip_suffix('\n').unwrap_or(&ccode).to_string();
+        self.push_stmt(CStmt::Raw(text));
     }
 
     /// 创建函数声明
@@ -83,20 +98,25 @@ impl CFunction {
     /// assert_eq!("int add(int a, int b);", func.get_generated_function_body_ccode());
     /// ```
     pub fn create_function_declarations(&mut self) {
-        let mut declarations = String::new();
-        declarations.push_str(self.return_type);
-        declarations.push_str(" ");
-        declarations.push_str(&self.name);
-        declarations.push_str("(");
+        let declarations = format!("{};", self.create_function_signature());
+        self.set_generated_function_body_ccode(declarations);
+    }
+
+    /// 生成函数签名（返回值类型、函数名与参数列表），不含结尾分号或花括号。
+    fn create_function_signature(&self) -> String {
+        let mut signature = String::new();
+        signature.push_str(&self.return_type.render(""));
+        signature.push_str(" ");
+        signature.push_str(&self.name);
+        signature.push_str("(");
         for (i, param) in self.parameters.iter().enumerate() {
             if i > 0 {
-                declarations.push_str(", ");
+                signature.push_str(", ");
             }
-            declarations.push_str(param);
+            signature.push_str(&param.render(""));
         }
-        declarations.push_str(");");
-
-        self.set_generated_function_body_ccode(declarations);
+        signature.push_str(")");
+        signature
     }
 
     /// 创建函数体开头
@@ -117,19 +137,7 @@ impl CFunction {
     /// assert_eq!("int add(int a, int b) {\n", func.get_generated_function_body_ccode());
     /// ```
     pub fn create_function_start(&mut self) {
-        let mut declarations = String::new();
-        declarations.push_str(self.return_type);
-        declarations.push_str(" ");
-        declarations.push_str(&self.name);
-        declarations.push_str("(");
-        for (i, param) in self.parameters.iter().enumerate() {
-            if i > 0 {
-                declarations.push_str(", ");
-            }
-            declarations.push_str(param);
-        }
-        declarations.push_str(") {\n");
-
+        let declarations = format!("{} {{\n", self.create_function_signature());
         self.set_generated_function_body_ccode(declarations);
     }
 
@@ -149,7 +157,10 @@ impl CFunction {
     ///
     /// func.create_function_call("add_long_long_ints", &["1000000000000", "2000000000000"]);
     ///
-    /// assert_eq!("add_long_long_ints(1000000000000, 2000000000000);\n", func.get_generated_function_body_ccode());
+    /// assert_eq!(
+    ///     "int add(int a, int b) {\n    add_long_long_ints(1000000000000, 2000000000000);\n}\n",
+    ///     func.build()
+    /// );
     /// ```
     ///
     /// 2. 调用一个内置函数
@@ -165,24 +176,24 @@ impl CFunction {
     ///
     /// func.create_function_call("printf", &["\"%d + %d = %d\\n\"", "a", "b"]);
     ///
-    /// assert_eq!("printf(\"%d + %d = %d\\n\", a, b);\n", func.get_generated_function_body_ccode());
+    /// assert_eq!(
+    ///     "int add(int a, int b) {\n    printf(\"%d + %d = %d\\n\", a, b);\n}\n",
+    ///     func.build()
+    /// );
     /// ```
     pub fn create_function_call(&mut self, function_name: &str, args: &[&str]) {
-        let mut call = String::new();
-        call.push_str(function_name);
-        call.push_str("(");
-        for (i, arg) in args.iter().enumerate() {
-            if i > 0 {
-                call.push_str(", ");
-            }
-            call.push_str(arg);
-        }
-        call.push_str(");\n");
-
-        self.append_generated_function_body_ccode(call);
+        self.push_stmt(CStmt::Call {
+            fn_name: function_name.to_string(),
+            args: args.iter().map(|arg| CExpr::Raw(arg.to_string())).collect(),
+        });
     }
 
-    /// 创建函数体结尾
+    /// 创建函数体结尾。
+    ///
+    /// 这会把整个函数（签名加上语句树展开出的函数体）渲染进内部缓冲区，
+    /// 因此即便函数体是通过 [`CFunction::append_generated_function_body_ccode`]
+    /// 或 [`CFunction::create_function_call`] 追加的，这里也都会一并渲染出来，
+    /// 不会出现缓冲区与语句树各说各话、内容被丢弃的情况。
     ///
     /// # 示例
     /// ```
@@ -197,10 +208,64 @@ impl CFunction {
     ///
     /// func.create_function_end();
     ///
-    /// assert_eq!("}\n", func.get_generated_function_body_ccode());
+    /// assert_eq!("int add(int a, int b) {\n}\n", func.get_generated_function_body_ccode());
     /// ```
     pub fn create_function_end(&mut self) {
-        self.append_generated_function_body_ccode("}\n".to_string());
+        self.build();
+    }
+
+    /// 向函数体的语句树追加一条语句。
+    ///
+    /// # 示例
+    /// ```
+    /// use ccgenor::{ CFunction, CStmt, CExpr, CType, cvartypes };
+    ///
+    /// let mut func = CFunction::new(
+    ///     "add".to_string(),
+    ///     cvartypes::C_INT,
+    ///     vec!["int a", "int b"]
+    /// );
+    /// func.push_stmt(CStmt::Return(Some(CExpr::Raw("a + b".to_string()))));
+    /// assert_eq!(1, func.get_body().len());
+    /// ```
+    pub fn push_stmt(&mut self, stmt: CStmt) {
+        self.body.push(stmt);
+    }
+
+    pub fn get_body(&self) -> &[CStmt] {
+        &self.body
+    }
+
+    /// 渲染完整的函数定义：函数签名加上由语句树展开的函数体。
+    ///
+    /// 这是 [`CFunction::create_function_start`] / [`CFunction::create_function_end`]
+    /// 这对字符串方法在语句树时代的替代品；它同时把结果写入内部缓冲区，
+    /// 因此之后可以直接用 [`crate::Context::add_function`] 收集。
+    ///
+    /// # 示例
+    /// ```
+    /// use ccgenor::{ CFunction, CStmt, CExpr, cvartypes };
+    ///
+    /// let mut func = CFunction::new(
+    ///     "add".to_string(),
+    ///     cvartypes::C_INT,
+    ///     vec!["int a", "int b"]
+    /// );
+    /// func.push_stmt(CStmt::Return(Some(CExpr::Raw("a + b".to_string()))));
+    ///
+    /// assert_eq!(
+    ///     "int add(int a, int b) {\n    return a + b;\n}\n",
+    ///     func.build()
+    /// );
+    /// ```
+    pub fn build(&mut self) -> &str {
+        let rendered = format!(
+            "{} {{\n{}}}\n",
+            self.create_function_signature(),
+            render_block(&self.body, 1)
+        );
+        self.set_generated_function_body_ccode(rendered);
+        self.get_generated_function_body_ccode()
     }
 }
 
@@ -218,10 +283,10 @@ mod tests {
             vec!["int a", "int b"]
         );
         assert_eq!("add", func.get_name());
-        assert_eq!(cvartypes::C_INT, *func.get_return_type());
+        assert_eq!(CType::from(cvartypes::C_INT), *func.get_return_type());
         assert_eq!(2, func.get_parameters().len());
-        assert_eq!("int a", func.get_parameters()[0]);
-        assert_eq!("int b", func.get_parameters()[1]);
+        assert_eq!(CType::from("int a"), func.get_parameters()[0]);
+        assert_eq!(CType::from("int b"), func.get_parameters()[1]);
         assert_eq!("", func.get_generated_function_body_ccode());
     }
 
@@ -247,6 +312,46 @@ mod tests {
         assert_eq!("int add(int a, int b);", func.get_generated_function_body_ccode());
     }
 
+    #[test]
+    fn test_build_from_stmt_tree() {
+        use crate::{ CExpr, CStmt, CType };
+
+        let mut func = CFunction::new("max".to_string(), cvartypes::C_INT, vec!["int a", "int b"]);
+        func.push_stmt(CStmt::If {
+            cond: CExpr::Raw("a > b".to_string()),
+            then_body: vec![CStmt::Return(Some(CExpr::Raw("a".to_string())))],
+            else_body: Some(vec![CStmt::Return(Some(CExpr::Raw("b".to_string())))]),
+        });
+        assert_eq!(
+            "int max(int a, int b) {\n    if (a > b) {\n        return a;\n    } else {\n        return b;\n    }\n}\n",
+            func.build()
+        );
+
+        // 语句树仍可用于渲染变量声明
+        let mut f2 = CFunction::new("f".to_string(), CType::Void, Vec::<CType>::new());
+        f2.push_stmt(CStmt::VarDecl {
+            ty: CType::I32,
+            name: "x".to_string(),
+            init: Some(CExpr::Raw("0".to_string())),
+        });
+        assert_eq!("void f() {\n    i32 x = 0;\n}\n", f2.build());
+    }
+
+    #[test]
+    fn test_legacy_start_append_call_end() {
+        // 旧式工作流：start -> append/call -> end，随后直接读取缓冲区，
+        // 不调用 build()，追加的内容也不应被丢弃。
+        let mut func = CFunction::new("add".to_string(), cvartypes::C_INT, vec!["int a", "int b"]);
+        func.create_function_start();
+        func.append_generated_function_body_ccode("int x = a + b;\n".to_string());
+        func.create_function_call("printf", &["\"%d\"", "x"]);
+        func.create_function_end();
+        assert_eq!(
+            "int add(int a, int b) {\n    int x = a + b;\n    printf(\"%d\", x);\n}\n",
+            func.get_generated_function_body_ccode()
+        );
+    }
+
     #[test]
     fn test_create_function_declarations2() {
         let mut func = CFunction::new(
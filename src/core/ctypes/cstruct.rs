@@ -0,0 +1,159 @@
+//! 本文件提供了 CStruct 结构体的定义，用于描述 C 语言的结构体。
+//! 生成的定义总是使用 `struct` 关键字，不生成联合体（`union`）。
+//!
+//! 它与 [`crate::CFunction`] 一样是 `core::ctypes` 下的一个节点类型，
+//! 字段类型复用 [`crate::CType`]，因此成员可以是指针、定长数组等复杂类型。
+
+use crate::CType;
+
+/// CStruct 结构体用于描述一个 C 语言结构体的名字与有序的成员列表。
+pub struct CStruct {
+    name: String,
+    members: Vec<(CType, String)>,
+}
+
+impl CStruct {
+    /// 创建一个新的、没有任何成员的 CStruct 实例。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use ccgenor::CStruct;
+    ///
+    /// let point = CStruct::new("Point".to_string());
+    /// assert_eq!("Point", point.get_name());
+    /// ```
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_members(&self) -> &[(CType, String)] {
+        &self.members
+    }
+
+    /// 追加一个成员。`ty` 为成员类型，`field_name` 为成员名。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use ccgenor::{ CStruct, CType };
+    ///
+    /// let mut point = CStruct::new("Point".to_string());
+    /// point.add_member(CType::I32, "x");
+    /// point.add_member(CType::I32, "y");
+    /// assert_eq!(2, point.get_members().len());
+    /// ```
+    pub fn add_member<T>(&mut self, ty: T, field_name: &str)
+    where
+        T: Into<CType>,
+    {
+        self.members.push((ty.into(), field_name.to_string()));
+    }
+
+    /// 渲染成员列表，每个成员占一行、缩进四个空格、以分号结尾。
+    fn render_members(&self) -> String {
+        let mut body = String::new();
+        for (ty, field_name) in &self.members {
+            body.push_str("    ");
+            body.push_str(&ty.render(field_name));
+            body.push_str(";\n");
+        }
+        body
+    }
+
+    /// 生成具名结构体定义。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use ccgenor::{ CStruct, CType };
+    ///
+    /// let mut point = CStruct::new("Point".to_string());
+    /// point.add_member(CType::I32, "x");
+    /// point.add_member(CType::F32, "y");
+    /// assert_eq!(
+    ///     "struct Point {\n    i32 x;\n    f32 y;\n};\n",
+    ///     point.create_struct_definition()
+    /// );
+    /// ```
+    pub fn create_struct_definition(&self) -> String {
+        format!("struct {} {{\n{}}};\n", self.name, self.render_members())
+    }
+
+    /// 生成 `typedef struct { ... } Name;` 形式的匿名结构体类型别名。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use ccgenor::{ CStruct, CType };
+    ///
+    /// let mut point = CStruct::new("Point".to_string());
+    /// point.add_member(CType::I32, "x");
+    /// point.add_member(CType::F32, "y");
+    /// assert_eq!(
+    ///     "typedef struct {\n    i32 x;\n    f32 y;\n} Point;\n",
+    ///     point.create_typedef_struct()
+    /// );
+    /// ```
+    pub fn create_typedef_struct(&self) -> String {
+        format!("typedef struct {{\n{}}} {};\n", self.render_members(), self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let s = CStruct::new("Point".to_string());
+        assert_eq!("Point", s.get_name());
+        assert_eq!(0, s.get_members().len());
+    }
+
+    #[test]
+    fn test_create_struct_definition() {
+        let mut s = CStruct::new("Point".to_string());
+        s.add_member(CType::I32, "x");
+        s.add_member(CType::F32, "y");
+        assert_eq!(
+            "struct Point {\n    i32 x;\n    f32 y;\n};\n",
+            s.create_struct_definition()
+        );
+    }
+
+    #[test]
+    fn test_create_typedef_struct() {
+        let mut s = CStruct::new("Point".to_string());
+        s.add_member(CType::I32, "x");
+        s.add_member(CType::F32, "y");
+        assert_eq!(
+            "typedef struct {\n    i32 x;\n    f32 y;\n} Point;\n",
+            s.create_typedef_struct()
+        );
+    }
+
+    #[test]
+    fn test_member_with_pointer_and_array() {
+        let mut s = CStruct::new("Buffer".to_string());
+        s.add_member(
+            CType::PointerTo { is_const: false, inner: Box::new(CType::U8) },
+            "data",
+        );
+        s.add_member(
+            CType::Array { inner: Box::new(CType::I32), len: Some(4) },
+            "sizes",
+        );
+        assert_eq!(
+            "struct Buffer {\n    u8 *data;\n    i32 sizes[4];\n};\n",
+            s.create_struct_definition()
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! 本文件提供了 CType 枚举，用于以结构化的方式描述 C 语言的类型。
+//!
+//! 相比于 [`crate::cvartypes::c_type`] 这种纯字符串的表示方式，
+//! `CType` 可以表达指针、数组、函数指针等复杂的类型代数，
+//! 并且能够根据 C 语言的声明符规则，把类型正确地“环绕”在标识符周围。
+
+/// CType 描述一个 C 语言类型。
+///
+/// 这里沿用了常见的 C 类型代数：基础类型、定宽整数、指针、数组、
+/// 函数类型，以及面向用户自定义类型的 [`CType::TypeDef`] 兜底分支。
+///
+/// # 示例
+///
+/// ```
+/// use ccgenor::CType;
+///
+/// // const char *s
+/// let ty = CType::PointerTo {
+///     is_const: true,
+///     inner: Box::new(CType::Char),
+/// };
+/// assert_eq!("const char *s", ty.render("s"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CType {
+    Void,
+
+    I8,
+    I16,
+    I32,
+    I64,
+    ISize,
+
+    U8,
+    U16,
+    U32,
+    U64,
+    USize,
+
+    F32,
+    F64,
+
+    Bool,
+    Char,
+
+    /// 指向 `inner` 的指针，`is_const` 表示被指向的内容是否为 `const`。
+    PointerTo {
+        is_const: bool,
+        inner: Box<CType>,
+    },
+
+    /// 元素类型为 `inner` 的数组，`len` 为 `None` 时表示未指定长度（`[]`）。
+    Array {
+        inner: Box<CType>,
+        len: Option<usize>,
+    },
+
+    /// 函数类型，`return_value` 为 `None` 时表示返回 `void`。
+    Function {
+        arguments: Vec<CType>,
+        return_value: Option<Box<CType>>,
+    },
+
+    /// 用户自定义的、或是暂时无法结构化表达的类型名。
+    TypeDef(String),
+}
+
+impl CType {
+    /// 把类型“环绕”在标识符 `ident` 周围，生成一段合法的 C 声明符。
+    ///
+    /// 采用自内向外的渲染方式：从标识符出发，逐层套上指针、数组、函数
+    /// 等修饰；当指针的外层是数组或函数时，为当前声明符补上括号，
+    /// 这样指向数组的指针、函数指针等嵌套声明都能正确生成。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use ccgenor::CType;
+    ///
+    /// // 定宽整数沿用紧凑的 i32 词汇表：i32 (*fp)(i32, i32)
+    /// let fp = CType::PointerTo {
+    ///     is_const: false,
+    ///     inner: Box::new(CType::Function {
+    ///         arguments: vec![CType::I32, CType::I32],
+    ///         return_value: Some(Box::new(CType::I32)),
+    ///     }),
+    /// };
+    /// assert_eq!("i32 (*fp)(i32, i32)", fp.render("fp"));
+    ///
+    /// // i32 arr[5]
+    /// let arr = CType::Array {
+    ///     inner: Box::new(CType::I32),
+    ///     len: Some(5),
+    /// };
+    /// assert_eq!("i32 arr[5]", arr.render("arr"));
+    /// ```
+    pub fn render(&self, ident: &str) -> String {
+        self.render_declarator(ident.to_string(), false)
+    }
+
+    /// 声明符渲染的递归实现。
+    ///
+    /// `declarator` 是目前已经累积好的、位于类型名右侧的部分；
+    /// `is_const` 表示当前这一层类型（叶子类型或指针本身）是否带有 `const`
+    /// 限定（由外层指针传入）。
+    fn render_declarator(&self, declarator: String, is_const: bool) -> String {
+        match self {
+            CType::PointerTo { is_const: inner_const, inner } => {
+                let needs_parens = matches!(
+                    inner.as_ref(),
+                    CType::Array { .. } | CType::Function { .. }
+                );
+                let star = if is_const { "*const " } else { "*" };
+                let declarator = if needs_parens {
+                    format!("({}{})", star, declarator)
+                } else {
+                    format!("{}{}", star, declarator)
+                };
+                inner.render_declarator(declarator, *inner_const)
+            }
+            CType::Array { inner, len } => {
+                let declarator = match len {
+                    Some(len) => format!("{}[{}]", declarator, len),
+                    None => format!("{}[]", declarator),
+                };
+                inner.render_declarator(declarator, is_const)
+            }
+            CType::Function { arguments, return_value } => {
+                let args = arguments
+                    .iter()
+                    .map(|arg| arg.render(""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let declarator = format!("{}({})", declarator, args);
+                match return_value {
+                    Some(return_value) => return_value.render_declarator(declarator, is_const),
+                    None => CType::Void.render_declarator(declarator, is_const),
+                }
+            }
+            leaf => {
+                let name = leaf.leaf_name();
+                let base = if is_const {
+                    format!("const {}", name)
+                } else {
+                    name.to_string()
+                };
+                format!("{} {}", base, declarator).trim_end().to_string()
+            }
+        }
+    }
+
+    /// 返回叶子类型对应的 C 类型名。
+    ///
+    /// 定宽整数与浮点数沿用紧凑的词汇表（`i64`、`u32`、`f32` 等），
+    /// 与 [`crate::Context::add_stdint_typedefs`] 生成的 typedef 前言配套使用。
+    fn leaf_name(&self) -> &str {
+        match self {
+            CType::Void => "void",
+            CType::I8 => "i8",
+            CType::I16 => "i16",
+            CType::I32 => "i32",
+            CType::I64 => "i64",
+            CType::ISize => "isize",
+            CType::U8 => "u8",
+            CType::U16 => "u16",
+            CType::U32 => "u32",
+            CType::U64 => "u64",
+            CType::USize => "usize",
+            CType::F32 => "f32",
+            CType::F64 => "f64",
+            CType::Bool => "bool",
+            CType::Char => "char",
+            CType::TypeDef(name) => name,
+            // 指针/数组/函数不是叶子类型，不会走到这里。
+            CType::PointerTo { .. } | CType::Array { .. } | CType::Function { .. } => {
+                unreachable!("non-leaf type has no leaf name")
+            }
+        }
+    }
+}
+
+impl From<&'static str> for CType {
+    /// 让旧的 [`crate::cvartypes`] 字符串常量以及任意字面量类型名，
+    /// 都能无缝地转换成 `CType::TypeDef`，保证现有调用方不被破坏。
+    fn from(name: &'static str) -> Self {
+        CType::TypeDef(name.to_string())
+    }
+}
+
+impl From<String> for CType {
+    fn from(name: String) -> Self {
+        CType::TypeDef(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaf_render() {
+        assert_eq!("i32 x", CType::I32.render("x"));
+        assert_eq!("void", CType::Void.render(""));
+        assert_eq!("char c", CType::Char.render("c"));
+    }
+
+    #[test]
+    fn test_const_pointer() {
+        let ty = CType::PointerTo {
+            is_const: true,
+            inner: Box::new(CType::Char),
+        };
+        assert_eq!("const char *s", ty.render("s"));
+    }
+
+    #[test]
+    fn test_array() {
+        let ty = CType::Array {
+            inner: Box::new(CType::I32),
+            len: Some(5),
+        };
+        assert_eq!("i32 arr[5]", ty.render("arr"));
+    }
+
+    #[test]
+    fn test_function_pointer() {
+        let ty = CType::PointerTo {
+            is_const: false,
+            inner: Box::new(CType::Function {
+                arguments: vec![CType::I32, CType::I32],
+                return_value: Some(Box::new(CType::I32)),
+            }),
+        };
+        assert_eq!("i32 (*fp)(i32, i32)", ty.render("fp"));
+    }
+
+    #[test]
+    fn test_pointer_to_array() {
+        // i32 (*p)[3]
+        let ty = CType::PointerTo {
+            is_const: false,
+            inner: Box::new(CType::Array {
+                inner: Box::new(CType::I32),
+                len: Some(3),
+            }),
+        };
+        assert_eq!("i32 (*p)[3]", ty.render("p"));
+    }
+
+    #[test]
+    fn test_typedef_from_str() {
+        let ty: CType = "int a".into();
+        assert_eq!("int a", ty.render(""));
+        assert_eq!(CType::TypeDef("int a".to_string()), ty);
+    }
+}
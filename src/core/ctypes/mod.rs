@@ -1,11 +1,17 @@
 pub mod cfunction;
+pub mod cstruct;
+pub mod ctype;
+pub mod stmt;
 
 pub use cfunction::CFunction;
+pub use cstruct::CStruct;
+pub use ctype::CType;
+pub use stmt::{ CExpr, CStmt };
 pub use cvartypes::*;
 
-/// 这个 mod 主要是为了提供一些常用的 C 类型定义，方便使用。
-/// 对于一些复杂的类型，比如指针、数组、结构体等，这里暂时不提供,
-/// 请使用字符切片来表示这些类型。
+/// 这个 mod 提供了一批常用 C 基础类型名的字符串常量，方便直接拼接使用。
+/// 指针、数组、结构体等复杂类型请改用本模块旁边的 [`CType`] 枚举来表达，
+/// 它能正确处理声明符的嵌套与摆放。
 pub mod cvartypes {
     pub type c_type = &'static str;
 
@@ -0,0 +1,239 @@
+//! 本文件提供了 CStmt / CExpr 节点，用于以树形结构描述函数体，
+//! 而不再是单纯地往字符串缓冲区里拼接文本。
+//!
+//! 这让控制流、嵌套与缩进都能被结构化地表达出来，也正是本 crate
+//! “类似 LLVM IR 的树形结构” 这一目标的落脚点。
+
+use crate::CType;
+
+/// 每一层缩进所使用的空白。
+const INDENT_UNIT: &str = "    ";
+
+/// CExpr 描述一个 C 语言表达式。
+///
+/// 目前只区分函数调用与原样字符串两种形式，后者作为兜底，
+/// 可以容纳任意尚未结构化的表达式片段。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CExpr {
+    /// 原样写出的表达式文本。
+    Raw(String),
+
+    /// 函数调用 `fn_name(arg1, arg2, ...)`。
+    Call {
+        fn_name: String,
+        args: Vec<CExpr>,
+    },
+}
+
+impl CExpr {
+    /// 把表达式渲染成一行内的文本（不含缩进与分号）。
+    pub fn render(&self) -> String {
+        match self {
+            CExpr::Raw(text) => text.clone(),
+            CExpr::Call { fn_name, args } => {
+                let args = args
+                    .iter()
+                    .map(CExpr::render)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}({})", fn_name, args)
+            }
+        }
+    }
+}
+
+/// CStmt 描述一条 C 语言语句，可以嵌套出完整的控制流树。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CStmt {
+    /// 变量声明，`init` 为可选的初始化表达式。
+    VarDecl {
+        ty: CType,
+        name: String,
+        init: Option<CExpr>,
+    },
+
+    /// 赋值语句 `target = value;`。
+    Assign {
+        target: String,
+        value: CExpr,
+    },
+
+    /// 作为语句出现的函数调用 `fn_name(args);`。
+    Call {
+        fn_name: String,
+        args: Vec<CExpr>,
+    },
+
+    /// `return;` 或 `return expr;`。
+    Return(Option<CExpr>),
+
+    /// `if (cond) { ... } [else { ... }]`。
+    If {
+        cond: CExpr,
+        then_body: Vec<CStmt>,
+        else_body: Option<Vec<CStmt>>,
+    },
+
+    /// `while (cond) { ... }`。
+    While {
+        cond: CExpr,
+        body: Vec<CStmt>,
+    },
+
+    /// `for (init; cond; step) { ... }`。
+    ///
+    /// `cond` 与 `While` 保持一致，使用结构化的 [`CExpr`]；`init`/`step`
+    /// 分别是声明片段与步进片段，仍以文本给出。
+    For {
+        init: String,
+        cond: CExpr,
+        step: String,
+        body: Vec<CStmt>,
+    },
+
+    /// 原样写出的语句文本（不会自动补分号）。
+    Raw(String),
+}
+
+impl CStmt {
+    /// 把语句渲染成文本，`indent` 为当前所处的缩进层级。
+    ///
+    /// 每条语句自带行首缩进与行尾换行；嵌套块的内部语句缩进层级加一，
+    /// `{`/`}` 被放在正确的位置上。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use ccgenor::{ CStmt, CExpr, CType };
+    ///
+    /// let stmt = CStmt::VarDecl {
+    ///     ty: CType::I32,
+    ///     name: "x".to_string(),
+    ///     init: Some(CExpr::Raw("10".to_string())),
+    /// };
+    /// assert_eq!("    i32 x = 10;\n", stmt.render(1));
+    /// ```
+    pub fn render(&self, indent: usize) -> String {
+        let pad = INDENT_UNIT.repeat(indent);
+        match self {
+            CStmt::VarDecl { ty, name, init } => match init {
+                Some(init) => format!("{}{} = {};\n", pad, ty.render(name), init.render()),
+                None => format!("{}{};\n", pad, ty.render(name)),
+            },
+            CStmt::Assign { target, value } => {
+                format!("{}{} = {};\n", pad, target, value.render())
+            }
+            CStmt::Call { fn_name, args } => {
+                let call = CExpr::Call { fn_name: fn_name.clone(), args: args.clone() };
+                format!("{}{};\n", pad, call.render())
+            }
+            CStmt::Return(value) => match value {
+                Some(value) => format!("{}return {};\n", pad, value.render()),
+                None => format!("{}return;\n", pad),
+            },
+            CStmt::If { cond, then_body, else_body } => {
+                let mut out = format!("{}if ({}) {{\n", pad, cond.render());
+                out.push_str(&render_block(then_body, indent + 1));
+                out.push_str(&format!("{}}}", pad));
+                if let Some(else_body) = else_body {
+                    out.push_str(" else {\n");
+                    out.push_str(&render_block(else_body, indent + 1));
+                    out.push_str(&format!("{}}}", pad));
+                }
+                out.push('\n');
+                out
+            }
+            CStmt::While { cond, body } => {
+                let mut out = format!("{}while ({}) {{\n", pad, cond.render());
+                out.push_str(&render_block(body, indent + 1));
+                out.push_str(&format!("{}}}\n", pad));
+                out
+            }
+            CStmt::For { init, cond, step, body } => {
+                let mut out = format!("{}for ({}; {}; {}) {{\n", pad, init, cond.render(), step);
+                out.push_str(&render_block(body, indent + 1));
+                out.push_str(&format!("{}}}\n", pad));
+                out
+            }
+            CStmt::Raw(text) => format!("{}{}\n", pad, text),
+        }
+    }
+}
+
+/// 渲染一段语句序列，每条语句都位于 `indent` 缩进层级。
+pub(crate) fn render_block(body: &[CStmt], indent: usize) -> String {
+    let mut out = String::new();
+    for stmt in body {
+        out.push_str(&stmt.render(indent));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expr_call() {
+        let call = CExpr::Call {
+            fn_name: "add".to_string(),
+            args: vec![CExpr::Raw("1".to_string()), CExpr::Raw("2".to_string())],
+        };
+        assert_eq!("add(1, 2)", call.render());
+    }
+
+    #[test]
+    fn test_var_decl() {
+        let stmt = CStmt::VarDecl {
+            ty: CType::I32,
+            name: "x".to_string(),
+            init: Some(CExpr::Raw("10".to_string())),
+        };
+        assert_eq!("    i32 x = 10;\n", stmt.render(1));
+
+        let stmt = CStmt::VarDecl { ty: CType::F64, name: "y".to_string(), init: None };
+        assert_eq!("f64 y;\n", stmt.render(0));
+    }
+
+    #[test]
+    fn test_return_and_call() {
+        let ret = CStmt::Return(Some(CExpr::Raw("a + b".to_string())));
+        assert_eq!("    return a + b;\n", ret.render(1));
+
+        let call = CStmt::Call {
+            fn_name: "printf".to_string(),
+            args: vec![CExpr::Raw("\"%d\"".to_string()), CExpr::Raw("a".to_string())],
+        };
+        assert_eq!("    printf(\"%d\", a);\n", call.render(1));
+    }
+
+    #[test]
+    fn test_if_else_nesting() {
+        let stmt = CStmt::If {
+            cond: CExpr::Raw("a > b".to_string()),
+            then_body: vec![CStmt::Return(Some(CExpr::Raw("a".to_string())))],
+            else_body: Some(vec![CStmt::Return(Some(CExpr::Raw("b".to_string())))]),
+        };
+        assert_eq!(
+            "if (a > b) {\n    return a;\n} else {\n    return b;\n}\n",
+            stmt.render(0)
+        );
+    }
+
+    #[test]
+    fn test_for_loop() {
+        let stmt = CStmt::For {
+            init: "i32 i = 0".to_string(),
+            cond: CExpr::Raw("i < 10".to_string()),
+            step: "i++".to_string(),
+            body: vec![CStmt::Call {
+                fn_name: "work".to_string(),
+                args: vec![CExpr::Raw("i".to_string())],
+            }],
+        };
+        assert_eq!(
+            "for (i32 i = 0; i < 10; i++) {\n    work(i);\n}\n",
+            stmt.render(0)
+        );
+    }
+}
@@ -3,4 +3,4 @@
 
 pub mod core;
 pub use core::Context;
-pub use core::ctypes::{ CFunction, c_type, cvartypes };
+pub use core::ctypes::{ CExpr, CFunction, CStmt, CStruct, CType, c_type, cvartypes };